@@ -0,0 +1,32 @@
+//! Injectable time source
+//!
+//! `ts` generation and the [`crate::MAX_CLOCK_SKEW_MS`] /
+//! [`crate::MAX_RECEIPT_AGE_MS`] checks in [`crate::verifier`] need "now" in
+//! Unix milliseconds, but bare-metal and TEE targets often have no
+//! `std::time::SystemTime` to ask. A [`Clock`] lets callers on those targets
+//! supply their own time source (a secure monotonic counter, an enclave
+//! attestation clock, …) instead of the crate reaching for the system clock
+//! directly.
+
+/// A source of the current Unix time in milliseconds
+pub trait Clock {
+    /// The current time, in Unix milliseconds
+    fn now_ms(&self) -> i64;
+}
+
+/// A [`Clock`] backed by `std::time::SystemTime`, used by default whenever
+/// the `std` feature is enabled
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_ms(&self) -> i64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64
+    }
+}
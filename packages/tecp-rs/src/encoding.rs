@@ -0,0 +1,142 @@
+//! Compact `tecp1...` textual encoding for receipts
+//!
+//! Mirrors how BOLT11 lightning invoices give themselves a single
+//! self-contained string form: a receipt becomes a `tecp1`-prefixed bech32
+//! string that is safe to carry in URLs, HTTP headers, and QR codes, with a
+//! built-in checksum so truncation or typos are caught before the (much more
+//! expensive) signature verification ever runs.
+
+use crate::error::{Result, TECPError};
+use crate::receipt::canonical_bytes;
+use crate::types::FullReceipt;
+use crate::MAX_RECEIPT_SIZE_BYTES;
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::ToString, vec::Vec};
+use bech32::{FromBase32, ToBase32, Variant};
+use core::fmt;
+use core::str::FromStr;
+
+/// Human-readable part of the compact receipt encoding
+const HRP: &str = "tecp";
+
+/// Upper bound on the encoded string length for a `MAX_RECEIPT_SIZE_BYTES`
+/// payload: hrp + separator + 5-bit groups (ceil(bytes * 8 / 5)) + 6-char checksum.
+/// Used to reject oversized input before spending any work decoding it.
+const MAX_ENCODED_LEN: usize = HRP.len() + 1 + (MAX_RECEIPT_SIZE_BYTES * 8 + 4) / 5 + 6;
+
+impl fmt::Display for FullReceipt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = canonical_bytes(self).map_err(|_| fmt::Error)?;
+        let encoded =
+            bech32::encode(HRP, bytes.to_base32(), Variant::Bech32).map_err(|_| fmt::Error)?;
+        f.write_str(&encoded)
+    }
+}
+
+impl FromStr for FullReceipt {
+    type Err = TECPError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.len() > MAX_ENCODED_LEN {
+            return Err(TECPError::Schema {
+                code: "E-SCHEMA-003",
+                message: "Encoded receipt exceeds MAX_RECEIPT_SIZE_BYTES".to_string(),
+                field: Some("(encoded)".to_string()),
+            });
+        }
+
+        let (hrp, data, variant) = bech32::decode(s).map_err(|e| TECPError::Schema {
+            code: "E-SCHEMA-003",
+            message: format!("Invalid compact receipt encoding: {e}"),
+            field: None,
+        })?;
+
+        if hrp != HRP || variant != Variant::Bech32 {
+            return Err(TECPError::Schema {
+                code: "E-SCHEMA-003",
+                message: "Unrecognized receipt encoding prefix".to_string(),
+                field: None,
+            });
+        }
+
+        let bytes = Vec::<u8>::from_base32(&data).map_err(|e| TECPError::Schema {
+            code: "E-SCHEMA-003",
+            message: format!("Invalid compact receipt payload: {e}"),
+            field: None,
+        })?;
+
+        if bytes.len() > MAX_RECEIPT_SIZE_BYTES {
+            return Err(TECPError::Schema {
+                code: "E-SCHEMA-003",
+                message: "Receipt exceeds MAX_RECEIPT_SIZE_BYTES".to_string(),
+                field: Some("(encoded)".to_string()),
+            });
+        }
+
+        serde_json::from_slice(&bytes).map_err(TECPError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::Clock;
+    use crate::receipt::ReceiptSigner;
+    use ed25519_dalek::SigningKey;
+    use rand::rngs::OsRng;
+
+    struct FixedClock(i64);
+
+    impl Clock for FixedClock {
+        fn now_ms(&self) -> i64 {
+            self.0
+        }
+    }
+
+    fn sample_receipt() -> FullReceipt {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let verifying_key = signing_key.verifying_key();
+        let signer = ReceiptSigner::with_clock(signing_key, verifying_key, FixedClock(1_700_000_000_000));
+        signer
+            .create_receipt(
+                "git:abc123",
+                b"input",
+                b"output",
+                vec!["no_retention".to_string()],
+                None,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn round_trip_is_lossless() {
+        let receipt = sample_receipt();
+        let encoded = receipt.to_string();
+        let decoded: FullReceipt = encoded.parse().unwrap();
+        assert_eq!(decoded, receipt);
+    }
+
+    #[test]
+    fn flipped_character_is_rejected_by_checksum() {
+        let receipt = sample_receipt();
+        let mut encoded = receipt.to_string();
+
+        let flip_at = encoded.len() - 1;
+        let flipped_char = if encoded.as_bytes()[flip_at] == b'q' { 'p' } else { 'q' };
+        encoded.replace_range(flip_at..flip_at + 1, &flipped_char.to_string());
+
+        let err = encoded.parse::<FullReceipt>().unwrap_err();
+        assert_eq!(err.code(), "E-SCHEMA-003");
+    }
+
+    #[test]
+    fn oversized_input_is_rejected_without_decoding() {
+        // Not valid bech32 at all (there is no '1' separator) — if
+        // this were rejected for any reason other than the upfront length
+        // check, it would be a different error path than the one under test.
+        let oversized = "x".repeat(MAX_ENCODED_LEN + 1);
+        let err = oversized.parse::<FullReceipt>().unwrap_err();
+        assert_eq!(err.code(), "E-SCHEMA-003");
+        assert_eq!(err.field(), Some("(encoded)"));
+    }
+}
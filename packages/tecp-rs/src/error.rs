@@ -0,0 +1,86 @@
+//! Error types for the TECP SDK
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt;
+
+/// Convenience alias for results returned by this crate
+pub type Result<T> = core::result::Result<T, TECPError>;
+
+/// Errors that can occur while creating or verifying TECP receipts
+///
+/// Hand-written rather than `thiserror`-derived: `thiserror`'s derive only
+/// implements `core::error::Error` (rather than hard-coding
+/// `std::error::Error`) starting from 2.x, and this series makes no claim
+/// about which major version is pinned, so implementing `Display`/`Error`
+/// directly keeps the no_std build from depending on that.
+#[derive(Debug)]
+pub enum TECPError {
+    /// Signature is malformed or fails cryptographic verification
+    Signature { code: &'static str, message: String },
+    /// Timestamp is malformed, too old, or too far in the future
+    Timestamp { code: &'static str, message: String },
+    /// Receipt fails structural or format validation
+    Schema {
+        code: &'static str,
+        message: String,
+        field: Option<String>,
+    },
+    /// Transparency log inclusion proof is missing or invalid
+    Log { code: &'static str, message: String },
+    /// A receipt policy is unknown or unsatisfied
+    Policy { code: &'static str, message: String },
+    /// JSON (de)serialization failed
+    Serialization(serde_json::Error),
+}
+
+impl TECPError {
+    /// The stable machine-readable error code, matching [`crate::types::ERROR_CODES`]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Signature { code, .. }
+            | Self::Timestamp { code, .. }
+            | Self::Schema { code, .. }
+            | Self::Log { code, .. }
+            | Self::Policy { code, .. } => code,
+            Self::Serialization(_) => "E-SCHEMA-002",
+        }
+    }
+
+    /// The field that caused the error, if any
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::Schema { field, .. } => field.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TECPError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signature { code, message }
+            | Self::Timestamp { code, message }
+            | Self::Schema { code, message, .. }
+            | Self::Log { code, message }
+            | Self::Policy { code, message } => write!(f, "[{code}] {message}"),
+            Self::Serialization(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TECPError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Serialization(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for TECPError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Serialization(err)
+    }
+}
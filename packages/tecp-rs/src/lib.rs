@@ -3,6 +3,13 @@
 //! Trusted Ephemeral Computation Protocol implementation for Rust.
 //! Provides cryptographic receipts for verifiable, ephemeral computation.
 //!
+//! The `std` feature is enabled by default but can be turned off (keeping
+//! `alloc`) to build on bare-metal TEE and embedded targets. Disabling `std`
+//! also disables the system-clock-backed [`clock::SystemClock`] and the
+//! `std`-only constructors (e.g. `ReceiptSigner::new`,
+//! `ReceiptVerifier::new`) — supply your own [`clock::Clock`] via
+//! [`ReceiptSigner::with_clock`] / [`ReceiptVerifier::with_clock`] instead.
+//!
 //! ## Example
 //!
 //! ```rust
@@ -34,13 +41,29 @@
 //! # Ok(())
 //! # }
 //! ```
+#![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("tecp requires at least one of the `std` or `alloc` features to be enabled");
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
 
+pub mod clock;
+pub mod encoding;
+pub mod policy;
 pub mod receipt;
+pub mod trust;
 pub mod types;
 pub mod error;
 pub mod verifier;
 
-pub use receipt::ReceiptSigner;
+pub use clock::Clock;
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+
+pub use policy::{Policy, PolicyOutcome, PolicyRegistry};
+pub use receipt::{ReceiptBuilder, ReceiptSigner};
+pub use trust::{RootDocument, TrustStore, TrustedKey};
 pub use verifier::ReceiptVerifier;
 pub use types::{Receipt, FullReceipt, ReceiptExtensions, VerificationResult};
 pub use error::{TECPError, Result};
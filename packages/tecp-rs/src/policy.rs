@@ -0,0 +1,220 @@
+//! Policy registry and enforcement
+//!
+//! A receipt's `policy_ids` are otherwise just strings the caller claims to
+//! satisfy; nothing checks them. A [`PolicyRegistry`] maps policy IDs to
+//! predicates evaluated against the receipt (and its extensions) during
+//! verification, so e.g. `eu_region` actually inspects
+//! `extensions.environment.region` instead of being trusted at face value.
+//! Unrecognized policy IDs are reported rather than silently ignored.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::String, string::ToString, vec::Vec};
+use crate::types::FullReceipt;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+
+/// A single machine-checkable policy predicate
+pub trait Policy {
+    /// Evaluate whether `full` satisfies this policy
+    fn check(&self, full: &FullReceipt) -> PolicyOutcome;
+}
+
+/// Result of evaluating a single policy's predicate against a receipt
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyOutcome {
+    /// The policy's requirements are met
+    Satisfied,
+    /// The policy's requirements are not met, with a human-readable reason
+    Violated(String),
+}
+
+/// The policy ID and outcome from evaluating one entry of a receipt's `policy_ids`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyResult {
+    /// The policy ID as it appears on the receipt
+    pub policy_id: String,
+    /// Whether the policy was unrecognized, satisfied, or violated
+    pub outcome: PolicyResultKind,
+}
+
+/// Outcome kind for a single policy evaluation
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyResultKind {
+    /// The policy ID is not registered
+    Unknown,
+    /// The registered predicate ran and was satisfied
+    Satisfied,
+    /// The registered predicate ran and was violated, with a reason
+    Violated(String),
+}
+
+/// Maps policy IDs to the predicates that check them
+pub struct PolicyRegistry {
+    policies: HashMap<String, Box<dyn Policy + Send + Sync>>,
+}
+
+impl PolicyRegistry {
+    /// An empty registry with no policies registered; every `policy_id` on a
+    /// receipt will come back as [`PolicyResultKind::Unknown`]
+    pub fn empty() -> Self {
+        Self {
+            policies: HashMap::new(),
+        }
+    }
+
+    /// A registry pre-populated with TECP's built-in policies: `no_retention`,
+    /// `key_erasure_required`, and `eu_region`
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::empty();
+        registry.register("no_retention", NoRetention);
+        registry.register("key_erasure_required", KeyErasureRequired);
+        registry.register("eu_region", EuRegion);
+        registry
+    }
+
+    /// Register a custom policy under `policy_id`, replacing any existing
+    /// predicate registered under the same ID
+    pub fn register(
+        &mut self,
+        policy_id: impl Into<String>,
+        policy: impl Policy + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.policies.insert(policy_id.into(), Box::new(policy));
+        self
+    }
+
+    /// Evaluate every policy ID a receipt claims against the registry
+    pub(crate) fn evaluate(&self, full: &FullReceipt) -> Vec<PolicyResult> {
+        full.receipt
+            .policy_ids
+            .iter()
+            .map(|policy_id| {
+                let outcome = match self.policies.get(policy_id) {
+                    Some(policy) => match policy.check(full) {
+                        PolicyOutcome::Satisfied => PolicyResultKind::Satisfied,
+                        PolicyOutcome::Violated(reason) => PolicyResultKind::Violated(reason),
+                    },
+                    None => PolicyResultKind::Unknown,
+                };
+                PolicyResult {
+                    policy_id: policy_id.clone(),
+                    outcome,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Default for PolicyRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+/// `no_retention` has no receipt-visible evidence of retention to check
+/// against, so it is treated as an operational promise and always satisfied
+struct NoRetention;
+
+impl Policy for NoRetention {
+    fn check(&self, _full: &FullReceipt) -> PolicyOutcome {
+        PolicyOutcome::Satisfied
+    }
+}
+
+/// Requires a `key_erasure` extension to be present on the receipt
+struct KeyErasureRequired;
+
+impl Policy for KeyErasureRequired {
+    fn check(&self, full: &FullReceipt) -> PolicyOutcome {
+        if full.extensions.key_erasure.is_some() {
+            PolicyOutcome::Satisfied
+        } else {
+            PolicyOutcome::Violated("no key_erasure extension present".to_string())
+        }
+    }
+}
+
+/// Requires `extensions.environment.region` to be `"eu"`, compared
+/// case-insensitively
+struct EuRegion;
+
+impl Policy for EuRegion {
+    fn check(&self, full: &FullReceipt) -> PolicyOutcome {
+        let region = full
+            .extensions
+            .environment
+            .as_ref()
+            .and_then(|env| env.region.as_deref());
+        match region {
+            Some(region) if region.eq_ignore_ascii_case("eu") => PolicyOutcome::Satisfied,
+            Some(region) => PolicyOutcome::Violated(format!("environment.region is '{region}', not 'eu'")),
+            None => PolicyOutcome::Violated("no environment.region present".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Environment, Receipt, ReceiptExtensions};
+
+    fn receipt_with(policy_ids: Vec<&str>, extensions: ReceiptExtensions) -> FullReceipt {
+        FullReceipt {
+            receipt: Receipt {
+                version: "TECP-0.1".to_string(),
+                code_ref: "git:abc123".to_string(),
+                ts: 0,
+                nonce: String::new(),
+                input_hash: String::new(),
+                output_hash: String::new(),
+                policy_ids: policy_ids.into_iter().map(String::from).collect(),
+                sig: String::new(),
+                pubkey: String::new(),
+            },
+            extensions,
+        }
+    }
+
+    #[test]
+    fn key_erasure_required_violated_without_extension() {
+        let full = receipt_with(vec!["key_erasure_required"], ReceiptExtensions::default());
+        let results = PolicyRegistry::with_defaults().evaluate(&full);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].policy_id, "key_erasure_required");
+        assert!(matches!(results[0].outcome, PolicyResultKind::Violated(_)));
+    }
+
+    #[test]
+    fn unregistered_policy_id_is_unknown() {
+        let full = receipt_with(vec!["some_policy_nobody_registered"], ReceiptExtensions::default());
+        let results = PolicyRegistry::with_defaults().evaluate(&full);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].outcome, PolicyResultKind::Unknown);
+    }
+
+    #[test]
+    fn eu_region_is_case_insensitive() {
+        let mut extensions = ReceiptExtensions::default();
+        extensions.environment = Some(Environment {
+            region: Some("EU".to_string()),
+            provider: None,
+        });
+        let full = receipt_with(vec!["eu_region"], extensions);
+        let results = PolicyRegistry::with_defaults().evaluate(&full);
+        assert_eq!(results[0].outcome, PolicyResultKind::Satisfied);
+    }
+
+    #[test]
+    fn eu_region_rejects_other_regions() {
+        let mut extensions = ReceiptExtensions::default();
+        extensions.environment = Some(Environment {
+            region: Some("us".to_string()),
+            provider: None,
+        });
+        let full = receipt_with(vec!["eu_region"], extensions);
+        let results = PolicyRegistry::with_defaults().evaluate(&full);
+        assert!(matches!(results[0].outcome, PolicyResultKind::Violated(_)));
+    }
+}
@@ -0,0 +1,296 @@
+//! Receipt creation and signing
+
+use crate::clock::Clock;
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
+use crate::error::{Result, TECPError};
+use crate::types::{
+    CreateReceiptParams, Environment, FullReceipt, KeyErasureProof, KeyErasureScheme, Receipt,
+    ReceiptExtensions,
+};
+use crate::TECP_VERSION;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::String, string::ToString, vec::Vec};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// Signs ephemeral computation receipts with an Ed25519 key pair
+pub struct ReceiptSigner {
+    signing_key: SigningKey,
+    verifying_key: VerifyingKey,
+    clock: Box<dyn Clock + Send + Sync>,
+}
+
+impl ReceiptSigner {
+    /// Create a new signer from an existing Ed25519 key pair, using the
+    /// system clock for receipt timestamps
+    #[cfg(feature = "std")]
+    pub fn new(signing_key: SigningKey, verifying_key: VerifyingKey) -> Self {
+        Self::with_clock(signing_key, verifying_key, SystemClock)
+    }
+
+    /// Create a new signer with an explicit time source, for targets without
+    /// `std::time::SystemTime` (TEEs, embedded runtimes)
+    pub fn with_clock(
+        signing_key: SigningKey,
+        verifying_key: VerifyingKey,
+        clock: impl Clock + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            signing_key,
+            verifying_key,
+            clock: Box::new(clock),
+        }
+    }
+
+    /// Create and sign a receipt for the given computation
+    pub fn create_receipt(
+        &self,
+        code_ref: &str,
+        input_data: &[u8],
+        output_data: &[u8],
+        policy_ids: Vec<String>,
+        extensions: Option<ReceiptExtensions>,
+    ) -> Result<FullReceipt> {
+        self.create_receipt_with_params(CreateReceiptParams {
+            code_ref,
+            input_data,
+            output_data,
+            policy_ids,
+            extensions,
+            timestamp: None,
+            nonce: None,
+        })
+    }
+
+    /// Create and sign a receipt using explicit parameters, overriding the
+    /// default timestamp and nonce generation when set
+    pub fn create_receipt_with_params(&self, params: CreateReceiptParams) -> Result<FullReceipt> {
+        let mut receipt = Receipt {
+            version: TECP_VERSION.to_string(),
+            code_ref: params.code_ref.to_string(),
+            ts: params.timestamp.unwrap_or_else(|| self.clock.now_ms()),
+            nonce: params.nonce.unwrap_or_else(random_nonce),
+            input_hash: BASE64.encode(Sha256::digest(params.input_data)),
+            output_hash: BASE64.encode(Sha256::digest(params.output_data)),
+            policy_ids: params.policy_ids,
+            sig: String::new(),
+            pubkey: BASE64.encode(self.verifying_key.as_bytes()),
+        };
+
+        let extensions = params.extensions.unwrap_or_default();
+        let signature = self
+            .signing_key
+            .sign(&signing_payload(&receipt, &extensions)?);
+        receipt.sig = BASE64.encode(signature.to_bytes());
+
+        Ok(FullReceipt { receipt, extensions })
+    }
+}
+
+/// Fluent, validating constructor for receipts
+///
+/// Prefer this over filling in a [`CreateReceiptParams`] by hand: required
+/// fields are checked at [`build`](ReceiptBuilder::build) time instead of
+/// silently defaulting, and extensions are assembled incrementally instead
+/// of as a single all-or-nothing [`ReceiptExtensions`].
+#[derive(Debug, Default)]
+pub struct ReceiptBuilder {
+    code_ref: Option<String>,
+    input_data: Option<Vec<u8>>,
+    output_data: Option<Vec<u8>>,
+    policy_ids: Vec<String>,
+    extensions: ReceiptExtensions,
+    timestamp: Option<i64>,
+    nonce: Option<String>,
+}
+
+impl ReceiptBuilder {
+    /// Start building a receipt for the given computation reference
+    pub fn new(code_ref: impl Into<String>) -> Self {
+        Self {
+            code_ref: Some(code_ref.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the raw input data to be hashed into the receipt
+    pub fn input(mut self, input_data: impl Into<Vec<u8>>) -> Self {
+        self.input_data = Some(input_data.into());
+        self
+    }
+
+    /// Set the raw output data to be hashed into the receipt
+    pub fn output(mut self, output_data: impl Into<Vec<u8>>) -> Self {
+        self.output_data = Some(output_data.into());
+        self
+    }
+
+    /// Append a policy identifier; call repeatedly to attach several
+    pub fn policy(mut self, policy_id: impl Into<String>) -> Self {
+        self.policy_ids.push(policy_id.into());
+        self
+    }
+
+    /// Attach a key-erasure proof extension
+    pub fn with_key_erasure(mut self, scheme: KeyErasureScheme, evidence: impl Into<String>) -> Self {
+        self.extensions.key_erasure = Some(KeyErasureProof {
+            scheme,
+            evidence: evidence.into(),
+        });
+        self
+    }
+
+    /// Attach an environment metadata extension
+    pub fn with_environment(mut self, region: Option<String>, provider: Option<String>) -> Self {
+        self.extensions.environment = Some(Environment { region, provider });
+        self
+    }
+
+    /// Override the default (current-time) timestamp, mainly for tests
+    pub fn timestamp(mut self, ts: i64) -> Self {
+        self.timestamp = Some(ts);
+        self
+    }
+
+    /// Override the default (random) nonce, mainly for tests
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+
+    /// Validate the accumulated fields and sign the receipt
+    pub fn build(self, signer: &ReceiptSigner) -> Result<FullReceipt> {
+        let code_ref = self.code_ref.ok_or_else(|| missing_field("code_ref"))?;
+        let input_data = self.input_data.ok_or_else(|| missing_field("input_data"))?;
+        let output_data = self
+            .output_data
+            .ok_or_else(|| missing_field("output_data"))?;
+        if self.policy_ids.is_empty() {
+            return Err(missing_field("policy_ids"));
+        }
+
+        signer.create_receipt_with_params(CreateReceiptParams {
+            code_ref: &code_ref,
+            input_data: &input_data,
+            output_data: &output_data,
+            policy_ids: self.policy_ids,
+            extensions: Some(self.extensions),
+            timestamp: self.timestamp,
+            nonce: self.nonce,
+        })
+    }
+}
+
+fn missing_field(field: &'static str) -> TECPError {
+    TECPError::Schema {
+        code: "E-SCHEMA-001",
+        message: format!("Missing required field: {field}"),
+        field: Some(field.to_string()),
+    }
+}
+
+/// The bytes the signature is computed over: the canonical receipt with `sig`
+/// blanked and `extensions.log_inclusion` excluded. A transparency log can
+/// only issue a proof for content that existed before the proof did, so the
+/// proof can never have been part of what was actually signed.
+pub(crate) fn signing_payload(receipt: &Receipt, extensions: &ReceiptExtensions) -> Result<Vec<u8>> {
+    let mut unsigned = receipt.clone();
+    unsigned.sig = String::new();
+    let mut extensions = extensions.clone();
+    extensions.log_inclusion = None;
+    canonical_bytes(&FullReceipt {
+        receipt: unsigned,
+        extensions,
+    })
+}
+
+/// The canonical byte representation of a fully-signed receipt, used for the
+/// wire encoding in [`crate::encoding`]
+pub(crate) fn canonical_bytes(full: &FullReceipt) -> Result<Vec<u8>> {
+    Ok(serde_json::to_vec(full)?)
+}
+
+/// The canonical bytes of a receipt as a transparency log would have hashed
+/// it: identical to [`canonical_bytes`] but with `extensions.log_inclusion`
+/// cleared, for the same reason [`signing_payload`] clears it — a log can
+/// never have included a proof of its own inclusion in what it logged.
+pub(crate) fn logged_bytes(full: &FullReceipt) -> Result<Vec<u8>> {
+    let mut full = full.clone();
+    full.extensions.log_inclusion = None;
+    canonical_bytes(&full)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::KeyErasureScheme;
+    use rand::rngs::OsRng;
+
+    struct FixedClock(i64);
+
+    impl Clock for FixedClock {
+        fn now_ms(&self) -> i64 {
+            self.0
+        }
+    }
+
+    fn test_signer() -> ReceiptSigner {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let verifying_key = signing_key.verifying_key();
+        ReceiptSigner::with_clock(signing_key, verifying_key, FixedClock(1_700_000_000_000))
+    }
+
+    #[test]
+    fn builder_happy_path_round_trips() {
+        let signer = test_signer();
+        let full = ReceiptBuilder::new("git:abc123")
+            .input(b"input".to_vec())
+            .output(b"output".to_vec())
+            .policy("no_retention")
+            .with_key_erasure(KeyErasureScheme::SoftwareSimulation, "evidence")
+            .timestamp(1_700_000_000_000)
+            .nonce("fixed-nonce")
+            .build(&signer)
+            .unwrap();
+
+        assert_eq!(full.receipt.code_ref, "git:abc123");
+        assert_eq!(full.receipt.policy_ids, vec!["no_retention".to_string()]);
+        assert_eq!(full.receipt.nonce, "fixed-nonce");
+        assert_eq!(full.receipt.ts, 1_700_000_000_000);
+        assert_eq!(
+            full.extensions.key_erasure.unwrap().scheme,
+            KeyErasureScheme::SoftwareSimulation
+        );
+    }
+
+    #[test]
+    fn builder_rejects_missing_required_field() {
+        let signer = test_signer();
+        let err = ReceiptBuilder::new("git:abc123")
+            .output(b"output".to_vec())
+            .policy("no_retention")
+            .build(&signer)
+            .unwrap_err();
+        assert_eq!(err.code(), "E-SCHEMA-001");
+    }
+
+    #[test]
+    fn builder_rejects_empty_policy_ids() {
+        let signer = test_signer();
+        let err = ReceiptBuilder::new("git:abc123")
+            .input(b"input".to_vec())
+            .output(b"output".to_vec())
+            .build(&signer)
+            .unwrap_err();
+        assert_eq!(err.code(), "E-SCHEMA-001");
+    }
+}
+
+fn random_nonce() -> String {
+    let mut bytes = [0u8; 16];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    BASE64.encode(bytes)
+}
@@ -0,0 +1,287 @@
+//! Signed trust-root subsystem for verifier public-key governance
+//!
+//! Borrows the TUF root-of-trust model: a versioned, signed root document
+//! lists the Ed25519 keys authorized to sign receipts, and rotating that set
+//! requires a threshold of signatures from keys in the *previous* root,
+//! rather than a hard redeploy.
+
+use crate::error::{Result, TECPError};
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, string::ToString, vec::Vec};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use ed25519_dalek::{Signature, Verifier as _, VerifyingKey};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(not(feature = "std"))]
+use hashbrown::{HashMap, HashSet};
+
+/// A single key authorized to sign receipts, with an optional validity window
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrustedKey {
+    /// Stable identifier for this key, independent of rotation
+    pub key_id: String,
+    /// Ed25519 public key (base64), matching [`crate::types::Receipt::pubkey`]
+    pub pubkey: String,
+    /// Unix milliseconds the key becomes valid from (inclusive), if bounded
+    pub valid_from: Option<i64>,
+    /// Unix milliseconds the key stops being valid (exclusive), if bounded
+    pub valid_until: Option<i64>,
+}
+
+impl TrustedKey {
+    fn covers(&self, ts: i64) -> bool {
+        self.valid_from.map_or(true, |from| ts >= from)
+            && self.valid_until.map_or(true, |until| ts < until)
+    }
+}
+
+/// A versioned, signed statement of which keys are currently trusted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RootDocument {
+    /// Monotonically increasing version; a rotation must strictly increase this
+    pub version: u64,
+    /// Minimum number of signatures from this root's keys required to accept
+    /// a rotation away from this root to its successor
+    pub threshold: usize,
+    /// Keys authorized to sign receipts under this root
+    pub keys: Vec<TrustedKey>,
+    /// Signatures over the canonical bytes of this document (key_id -> base64
+    /// signature), produced by keys from the *previous* root
+    #[serde(default)]
+    pub signatures: HashMap<String, String>,
+}
+
+impl RootDocument {
+    fn canonical_bytes(&self) -> Result<Vec<u8>> {
+        let mut unsigned = self.clone();
+        unsigned.signatures.clear();
+        Ok(serde_json::to_vec(&unsigned)?)
+    }
+}
+
+/// Holds the currently trusted signer public keys, loaded from a signed,
+/// versioned root document
+#[derive(Debug, Clone)]
+pub struct TrustStore {
+    root: RootDocument,
+}
+
+impl TrustStore {
+    /// Bootstrap a trust store from an initial root document, trusted as-is
+    /// (it has no previous root to check signatures against, so this is
+    /// normally only used for the very first root, pinned at deployment time)
+    pub fn bootstrap(root: RootDocument) -> Self {
+        Self { root }
+    }
+
+    /// Atomically rotate to a new root document, requiring `threshold` valid
+    /// signatures from keys in the *current* root before accepting it
+    pub fn rotate(&mut self, new_root: RootDocument) -> Result<()> {
+        if new_root.version <= self.root.version {
+            return Err(TECPError::Signature {
+                code: "E-SIG-004",
+                message: "new root version must be greater than the current root version"
+                    .to_string(),
+            });
+        }
+
+        let payload = new_root.canonical_bytes()?;
+        // Track by key_id rather than counting matching keys directly: the
+        // previous root's `keys` list isn't guaranteed unique on key_id, and a
+        // single signature must not count twice toward the threshold just
+        // because it happens to validate against two entries for the same id.
+        let mut counted_signers = HashSet::new();
+        for key in &self.root.keys {
+            if new_root
+                .signatures
+                .get(&key.key_id)
+                .is_some_and(|sig| verify_with_key(key, &payload, sig).is_ok())
+            {
+                counted_signers.insert(key.key_id.clone());
+            }
+        }
+        let valid_signers = counted_signers.len();
+
+        if valid_signers < self.root.threshold {
+            return Err(TECPError::Signature {
+                code: "E-SIG-004",
+                message: format!(
+                    "root rotation requires {} valid signatures from the previous root, got {valid_signers}",
+                    self.root.threshold
+                ),
+            });
+        }
+
+        self.root = new_root;
+        Ok(())
+    }
+
+    /// Check whether a base64-encoded public key is trusted at the given timestamp
+    pub fn is_trusted(&self, pubkey: &str, ts: i64) -> bool {
+        self.root
+            .keys
+            .iter()
+            .any(|key| key.pubkey == pubkey && key.covers(ts))
+    }
+}
+
+fn verify_with_key(key: &TrustedKey, payload: &[u8], sig_b64: &str) -> Result<()> {
+    let pubkey_bytes = BASE64.decode(&key.pubkey).map_err(|e| TECPError::Signature {
+        code: "E-SIG-003",
+        message: format!("trusted key {} is not valid base64: {e}", key.key_id),
+    })?;
+    let pubkey_bytes: [u8; 32] = pubkey_bytes.try_into().map_err(|_| TECPError::Signature {
+        code: "E-SIG-003",
+        message: format!("trusted key {} must be 32 bytes", key.key_id),
+    })?;
+    let verifying_key =
+        VerifyingKey::from_bytes(&pubkey_bytes).map_err(|e| TECPError::Signature {
+            code: "E-SIG-003",
+            message: format!("trusted key {} is invalid: {e}", key.key_id),
+        })?;
+
+    let sig_bytes = BASE64.decode(sig_b64).map_err(|e| TECPError::Signature {
+        code: "E-SIG-001",
+        message: format!("root signature is not valid base64: {e}"),
+    })?;
+    let sig_bytes: [u8; 64] = sig_bytes.try_into().map_err(|_| TECPError::Signature {
+        code: "E-SIG-001",
+        message: "root signature must be 64 bytes".to_string(),
+    })?;
+    let signature = Signature::from_bytes(&sig_bytes);
+
+    verifying_key
+        .verify(payload, &signature)
+        .map_err(|_| TECPError::Signature {
+            code: "E-SIG-002",
+            message: "root signature verification failed".to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer as _, SigningKey};
+
+    fn signed_key(signing_key: &SigningKey, key_id: &str) -> TrustedKey {
+        TrustedKey {
+            key_id: key_id.to_string(),
+            pubkey: BASE64.encode(signing_key.verifying_key().to_bytes()),
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    fn sign_root(signing_key: &SigningKey, root: &RootDocument) -> String {
+        let payload = root.canonical_bytes().unwrap();
+        BASE64.encode(signing_key.sign(&payload).to_bytes())
+    }
+
+    fn root_with_signatures(
+        version: u64,
+        threshold: usize,
+        keys: Vec<TrustedKey>,
+        signatures: HashMap<String, String>,
+    ) -> RootDocument {
+        RootDocument {
+            version,
+            threshold,
+            keys,
+            signatures,
+        }
+    }
+
+    #[test]
+    fn rotate_succeeds_at_threshold() {
+        let k1 = SigningKey::from_bytes(&[1u8; 32]);
+        let k2 = SigningKey::from_bytes(&[2u8; 32]);
+        let genesis = root_with_signatures(
+            1,
+            2,
+            vec![signed_key(&k1, "k1"), signed_key(&k2, "k2")],
+            HashMap::new(),
+        );
+        let mut store = TrustStore::bootstrap(genesis);
+
+        let mut next = root_with_signatures(2, 1, vec![signed_key(&k1, "k1")], HashMap::new());
+        let sig1 = sign_root(&k1, &next);
+        let sig2 = sign_root(&k2, &next);
+        next.signatures.insert("k1".to_string(), sig1);
+        next.signatures.insert("k2".to_string(), sig2);
+
+        assert!(store.rotate(next.clone()).is_ok());
+        assert_eq!(store.root.version, 2);
+    }
+
+    #[test]
+    fn rotate_fails_below_threshold() {
+        let k1 = SigningKey::from_bytes(&[1u8; 32]);
+        let k2 = SigningKey::from_bytes(&[2u8; 32]);
+        let genesis = root_with_signatures(
+            1,
+            2,
+            vec![signed_key(&k1, "k1"), signed_key(&k2, "k2")],
+            HashMap::new(),
+        );
+        let mut store = TrustStore::bootstrap(genesis);
+
+        let mut next = root_with_signatures(2, 1, vec![signed_key(&k1, "k1")], HashMap::new());
+        let sig1 = sign_root(&k1, &next);
+        next.signatures.insert("k1".to_string(), sig1);
+
+        let err = store.rotate(next).unwrap_err();
+        assert_eq!(err.code(), "E-SIG-004");
+    }
+
+    #[test]
+    fn rotate_does_not_double_count_duplicate_key_id() {
+        // The previous root lists "k1" twice (e.g. re-added with a different
+        // validity window); a single valid signature from it must still only
+        // count once toward the threshold.
+        let k1 = SigningKey::from_bytes(&[1u8; 32]);
+        let genesis = root_with_signatures(
+            1,
+            2,
+            vec![signed_key(&k1, "k1"), signed_key(&k1, "k1")],
+            HashMap::new(),
+        );
+        let mut store = TrustStore::bootstrap(genesis);
+
+        let mut next = root_with_signatures(2, 2, vec![signed_key(&k1, "k1")], HashMap::new());
+        let sig1 = sign_root(&k1, &next);
+        next.signatures.insert("k1".to_string(), sig1);
+
+        let err = store.rotate(next).unwrap_err();
+        assert_eq!(err.code(), "E-SIG-004");
+    }
+
+    #[test]
+    fn rotate_rejects_non_increasing_version() {
+        let k1 = SigningKey::from_bytes(&[1u8; 32]);
+        let genesis = root_with_signatures(5, 1, vec![signed_key(&k1, "k1")], HashMap::new());
+        let mut store = TrustStore::bootstrap(genesis);
+
+        let next = root_with_signatures(5, 1, vec![signed_key(&k1, "k1")], HashMap::new());
+        let err = store.rotate(next).unwrap_err();
+        assert_eq!(err.code(), "E-SIG-004");
+    }
+
+    #[test]
+    fn is_trusted_respects_validity_window() {
+        let k1 = SigningKey::from_bytes(&[1u8; 32]);
+        let pubkey = BASE64.encode(k1.verifying_key().to_bytes());
+        let key = TrustedKey {
+            key_id: "k1".to_string(),
+            pubkey: pubkey.clone(),
+            valid_from: Some(1_000),
+            valid_until: Some(2_000),
+        };
+        let store = TrustStore::bootstrap(root_with_signatures(1, 1, vec![key], HashMap::new()));
+
+        assert!(!store.is_trusted(&pubkey, 999), "not yet valid");
+        assert!(store.is_trusted(&pubkey, 1_000), "start of window is inclusive");
+        assert!(store.is_trusted(&pubkey, 1_999));
+        assert!(!store.is_trusted(&pubkey, 2_000), "end of window is exclusive");
+    }
+}
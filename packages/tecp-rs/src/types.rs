@@ -1,7 +1,12 @@
 //! TECP Types and Data Structures
 
 use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, string::ToString, vec::Vec};
+#[cfg(feature = "std")]
 use std::collections::HashMap;
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
 
 /// TECP Receipt - Core required fields only
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -59,9 +64,11 @@ pub struct Environment {
 pub struct LogInclusion {
     /// Leaf index in transparency log
     pub leaf_index: u64,
-    /// Merkle inclusion proof
+    /// Size of the tree when the proof was issued
+    pub tree_size: u64,
+    /// Merkle inclusion proof (audit path, leaf to root sibling hashes, base64)
     pub merkle_proof: Vec<String>,
-    /// Signed log root hash
+    /// Signed log root hash (base64)
     pub log_root: String,
 }
 
@@ -98,6 +105,17 @@ pub struct VerificationError {
     pub field: Option<String>,
 }
 
+/// Pass/fail status of a single policy ID against the [`crate::policy::PolicyRegistry`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PolicyCheckDetail {
+    /// The policy ID as it appears on the receipt
+    pub policy_id: String,
+    /// "Satisfied", "Violated", or "Unknown policy ID"
+    pub status: String,
+    /// Reason the policy was violated; `None` for satisfied and unknown policies
+    pub message: Option<String>,
+}
+
 /// Detailed verification results
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VerificationDetails {
@@ -109,6 +127,8 @@ pub struct VerificationDetails {
     pub schema: String,
     /// Transparency log verification status
     pub transparency_log: String,
+    /// Per-policy verification status for each of the receipt's `policy_ids`
+    pub policies: Vec<PolicyCheckDetail>,
 }
 
 /// Receipt verification result
@@ -148,6 +168,7 @@ pub const ERROR_CODES: &[(&str, &str)] = &[
     ("E-SIG-001", "Invalid signature format"),
     ("E-SIG-002", "Signature verification failed"),
     ("E-SIG-003", "Public key format invalid"),
+    ("E-SIG-004", "Untrusted signer key"),
     ("E-TS-001", "Timestamp format invalid"),
     ("E-TS-002", "Clock skew exceeded (>5 minutes)"),
     ("E-TS-003", "Receipt expired (>24 hours)"),
@@ -173,6 +194,7 @@ impl Default for VerificationDetails {
             timestamp: "OK".to_string(),
             schema: "OK".to_string(),
             transparency_log: "Not checked".to_string(),
+            policies: Vec::new(),
         }
     }
 }
@@ -0,0 +1,440 @@
+//! Receipt verification
+
+use crate::clock::Clock;
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
+use crate::error::{Result, TECPError};
+use crate::policy::{PolicyRegistry, PolicyResultKind};
+use crate::receipt::{logged_bytes, signing_payload};
+use crate::trust::TrustStore;
+use crate::types::{FullReceipt, LogInclusion, PolicyCheckDetail, VerificationError, VerificationResult};
+use crate::{MAX_CLOCK_SKEW_MS, MAX_RECEIPT_AGE_MS};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use ed25519_dalek::{Signature, Verifier as _, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+/// Verifies TECP receipts: signature, timestamp bounds, schema, transparency-log
+/// inclusion, and policy satisfaction
+pub struct ReceiptVerifier {
+    trust_store: Option<TrustStore>,
+    policy_registry: PolicyRegistry,
+    clock: Box<dyn Clock + Send + Sync>,
+}
+
+impl ReceiptVerifier {
+    /// Create a new verifier that trusts whatever `pubkey` is embedded in the
+    /// receipt, using the system clock for timestamp checks
+    #[cfg(feature = "std")]
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+
+    /// Create a new verifier with an explicit time source, for targets
+    /// without `std::time::SystemTime` (TEEs, embedded runtimes)
+    pub fn with_clock(clock: impl Clock + Send + Sync + 'static) -> Self {
+        Self {
+            trust_store: None,
+            policy_registry: PolicyRegistry::with_defaults(),
+            clock: Box::new(clock),
+        }
+    }
+
+    /// Reject receipts signed by a key outside the given [`TrustStore`]'s
+    /// currently active set
+    pub fn with_trust_store(mut self, trust_store: TrustStore) -> Self {
+        self.trust_store = Some(trust_store);
+        self
+    }
+
+    /// Replace the default [`PolicyRegistry`] (built-in policies only) with a
+    /// custom one, e.g. to register additional policy IDs
+    pub fn with_policy_registry(mut self, policy_registry: PolicyRegistry) -> Self {
+        self.policy_registry = policy_registry;
+        self
+    }
+
+    /// Run all verification checks against a receipt
+    pub fn verify(&self, full: &FullReceipt) -> Result<VerificationResult> {
+        let mut result = VerificationResult::default();
+
+        match self.verify_signature(full) {
+            Ok(()) => result.details.signature = "Valid".to_string(),
+            Err(err) => {
+                result.details.signature = "Invalid".to_string();
+                result.errors.push(to_verification_error(err));
+            }
+        }
+
+        if let Err(err) = self.verify_timestamp(full) {
+            result.details.timestamp = "Invalid".to_string();
+            result.errors.push(to_verification_error(err));
+        }
+
+        match &full.extensions.log_inclusion {
+            Some(log_inclusion) => match self.verify_log_inclusion(full, log_inclusion) {
+                Ok(()) => result.details.transparency_log = "Verified".to_string(),
+                Err(err) => {
+                    result.details.transparency_log = "Invalid".to_string();
+                    result.errors.push(to_verification_error(err));
+                }
+            },
+            None => result.details.transparency_log = "Not checked".to_string(),
+        }
+
+        for policy_result in self.policy_registry.evaluate(full) {
+            let (status, message, error) = match policy_result.outcome {
+                PolicyResultKind::Satisfied => ("Satisfied".to_string(), None, None),
+                PolicyResultKind::Unknown => (
+                    "Unknown policy ID".to_string(),
+                    None,
+                    Some(TECPError::Policy {
+                        code: "E-POLICY-001",
+                        message: format!("Unknown policy ID: {}", policy_result.policy_id),
+                    }),
+                ),
+                PolicyResultKind::Violated(reason) => (
+                    "Violated".to_string(),
+                    Some(reason.clone()),
+                    Some(TECPError::Policy {
+                        code: "E-POLICY-003",
+                        message: format!("Policy '{}' not satisfied: {reason}", policy_result.policy_id),
+                    }),
+                ),
+            };
+
+            result.details.policies.push(PolicyCheckDetail {
+                policy_id: policy_result.policy_id,
+                status,
+                message,
+            });
+            if let Some(err) = error {
+                result.errors.push(to_verification_error(err));
+            }
+        }
+
+        result.valid = result.errors.is_empty();
+        Ok(result)
+    }
+
+    fn verify_signature(&self, full: &FullReceipt) -> Result<()> {
+        let pubkey_bytes = BASE64.decode(&full.receipt.pubkey).map_err(|e| TECPError::Signature {
+            code: "E-SIG-003",
+            message: format!("Public key is not valid base64: {e}"),
+        })?;
+        let pubkey_bytes: [u8; 32] = pubkey_bytes.try_into().map_err(|_| TECPError::Signature {
+            code: "E-SIG-003",
+            message: "Public key must be 32 bytes".to_string(),
+        })?;
+        let verifying_key = VerifyingKey::from_bytes(&pubkey_bytes).map_err(|e| TECPError::Signature {
+            code: "E-SIG-003",
+            message: format!("Public key is invalid: {e}"),
+        })?;
+
+        let sig_bytes = BASE64.decode(&full.receipt.sig).map_err(|e| TECPError::Signature {
+            code: "E-SIG-001",
+            message: format!("Signature is not valid base64: {e}"),
+        })?;
+        let sig_bytes: [u8; 64] = sig_bytes.try_into().map_err(|_| TECPError::Signature {
+            code: "E-SIG-001",
+            message: "Signature must be 64 bytes".to_string(),
+        })?;
+        let signature = Signature::from_bytes(&sig_bytes);
+
+        let payload = signing_payload(&full.receipt, &full.extensions)?;
+        verifying_key
+            .verify(&payload, &signature)
+            .map_err(|_| TECPError::Signature {
+                code: "E-SIG-002",
+                message: "Signature verification failed".to_string(),
+            })?;
+
+        if let Some(trust_store) = &self.trust_store {
+            if !trust_store.is_trusted(&full.receipt.pubkey, full.receipt.ts) {
+                return Err(TECPError::Signature {
+                    code: "E-SIG-004",
+                    message: "Untrusted signer key".to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn verify_timestamp(&self, full: &FullReceipt) -> Result<()> {
+        let now = self.clock.now_ms();
+        let ts = full.receipt.ts;
+
+        if ts > now + MAX_CLOCK_SKEW_MS {
+            return Err(TECPError::Timestamp {
+                code: "E-AGE-002",
+                message: "Receipt timestamp is in the future".to_string(),
+            });
+        }
+        if now - ts > MAX_RECEIPT_AGE_MS {
+            return Err(TECPError::Timestamp {
+                code: "E-AGE-001",
+                message: "Receipt is older than the maximum allowed age".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verify a log inclusion proof using the RFC 6962 Merkle audit-path algorithm
+    fn verify_log_inclusion(&self, full: &FullReceipt, log_inclusion: &LogInclusion) -> Result<()> {
+        let leaf_preimage = {
+            let mut bytes = vec![0x00u8];
+            bytes.extend_from_slice(&logged_bytes(full)?);
+            bytes
+        };
+        let leaf_hash = Sha256::digest(&leaf_preimage).to_vec();
+
+        let log_root = BASE64.decode(&log_inclusion.log_root).map_err(|e| TECPError::Log {
+            code: "E-LOG-002",
+            message: format!("Log root is not valid base64: {e}"),
+        })?;
+
+        let proof: core::result::Result<Vec<Vec<u8>>, _> = log_inclusion
+            .merkle_proof
+            .iter()
+            .map(|p| BASE64.decode(p))
+            .collect();
+        let proof = proof.map_err(|e| TECPError::Log {
+            code: "E-LOG-002",
+            message: format!("Merkle proof entry is not valid base64: {e}"),
+        })?;
+
+        let computed_root = root_from_inclusion_proof(log_inclusion.leaf_index, log_inclusion.tree_size, &proof, &leaf_hash)?;
+
+        if computed_root != log_root {
+            return Err(TECPError::Log {
+                code: "E-LOG-003",
+                message: "Computed Merkle root does not match signed log root".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// RFC 6962 audit-path algorithm: recompute the tree root from a leaf hash and its inclusion proof
+fn root_from_inclusion_proof(leaf_index: u64, tree_size: u64, proof: &[Vec<u8>], leaf_hash: &[u8]) -> Result<Vec<u8>> {
+    if tree_size == 0 {
+        return Err(TECPError::Log {
+            code: "E-LOG-002",
+            message: "Tree size must be greater than zero".to_string(),
+        });
+    }
+
+    let mut fn_ = leaf_index;
+    let mut sn = tree_size - 1;
+    let mut r = leaf_hash.to_vec();
+
+    for p in proof {
+        if sn == 0 {
+            return Err(TECPError::Log {
+                code: "E-LOG-002",
+                message: "Merkle proof is longer than the tree height allows".to_string(),
+            });
+        }
+        if fn_ & 1 == 1 || fn_ == sn {
+            r = hash_children(p, &r);
+            while fn_ & 1 == 0 && fn_ != 0 {
+                fn_ >>= 1;
+                sn >>= 1;
+            }
+        } else {
+            r = hash_children(&r, p);
+        }
+        fn_ >>= 1;
+        sn >>= 1;
+    }
+
+    if sn != 0 {
+        return Err(TECPError::Log {
+            code: "E-LOG-002",
+            message: "Merkle proof is shorter than the tree height requires".to_string(),
+        });
+    }
+    Ok(r)
+}
+
+fn hash_children(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().to_vec()
+}
+
+fn to_verification_error(err: TECPError) -> VerificationError {
+    VerificationError {
+        code: err.code().to_string(),
+        message: err.to_string(),
+        field: err.field().map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::receipt::ReceiptSigner;
+    use ed25519_dalek::SigningKey;
+    use rand::rngs::OsRng;
+
+    fn leaf(b: u8) -> Vec<u8> {
+        Sha256::digest([0x00u8, b]).to_vec()
+    }
+
+    struct FixedClock(i64);
+
+    impl Clock for FixedClock {
+        fn now_ms(&self) -> i64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn log_inclusion_round_trips_through_signing_and_verification() {
+        // The leaf a log could plausibly have hashed predates the proof it
+        // later hands back, so it must exclude `extensions.log_inclusion` —
+        // attach the proof only *after* computing the leaf hash, mirroring
+        // how a real transparency log workflow would work.
+        let ts = 1_700_000_000_000i64;
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let verifying_key = signing_key.verifying_key();
+        let signer = ReceiptSigner::with_clock(signing_key, verifying_key, FixedClock(ts));
+
+        let signed = signer
+            .create_receipt(
+                "git:abc123",
+                b"input",
+                b"output",
+                vec!["no_retention".to_string()],
+                None,
+            )
+            .unwrap();
+
+        let mut leaf_preimage = vec![0x00u8];
+        leaf_preimage.extend_from_slice(&logged_bytes(&signed).unwrap());
+        let leaf_hash = Sha256::digest(&leaf_preimage).to_vec();
+
+        let mut receipt = signed;
+        receipt.extensions.log_inclusion = Some(LogInclusion {
+            leaf_index: 0,
+            tree_size: 1,
+            merkle_proof: Vec::new(),
+            log_root: BASE64.encode(&leaf_hash),
+        });
+
+        let verifier = ReceiptVerifier::with_clock(FixedClock(ts));
+        let result = verifier.verify(&receipt).unwrap();
+        assert!(result.valid, "unexpected errors: {:?}", result.errors);
+        assert_eq!(result.details.signature, "Valid");
+        assert_eq!(result.details.transparency_log, "Verified");
+    }
+
+    fn signed_receipt_with_policies(ts: i64, policy_ids: Vec<&str>) -> FullReceipt {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let verifying_key = signing_key.verifying_key();
+        let signer = ReceiptSigner::with_clock(signing_key, verifying_key, FixedClock(ts));
+        signer
+            .create_receipt(
+                "git:abc123",
+                b"input",
+                b"output",
+                policy_ids.into_iter().map(|id| id.to_string()).collect(),
+                None,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn unsatisfied_policy_reports_e_policy_003() {
+        let ts = 1_700_000_000_000i64;
+        let full = signed_receipt_with_policies(ts, vec!["key_erasure_required"]);
+
+        let result = ReceiptVerifier::with_clock(FixedClock(ts)).verify(&full).unwrap();
+        assert!(!result.valid);
+        assert!(result.errors.iter().any(|e| e.code == "E-POLICY-003"));
+    }
+
+    #[test]
+    fn unknown_policy_reports_e_policy_001() {
+        let ts = 1_700_000_000_000i64;
+        let full = signed_receipt_with_policies(ts, vec!["not_a_real_policy"]);
+
+        let result = ReceiptVerifier::with_clock(FixedClock(ts)).verify(&full).unwrap();
+        assert!(!result.valid);
+        assert!(result.errors.iter().any(|e| e.code == "E-POLICY-001"));
+    }
+
+    #[test]
+    fn single_leaf_tree_root_is_the_leaf_hash() {
+        let leaf_hash = leaf(1);
+        let root = root_from_inclusion_proof(0, 1, &[], &leaf_hash).unwrap();
+        assert_eq!(root, leaf_hash);
+    }
+
+    #[test]
+    fn valid_proof_for_two_leaf_tree() {
+        // tree: root = hash_children(leaf0, leaf1)
+        let leaf0 = leaf(1);
+        let leaf1 = leaf(2);
+        let expected_root = hash_children(&leaf0, &leaf1);
+
+        let root_for_leaf0 =
+            root_from_inclusion_proof(0, 2, &[leaf1.clone()], &leaf0).unwrap();
+        assert_eq!(root_for_leaf0, expected_root);
+
+        let root_for_leaf1 = root_from_inclusion_proof(1, 2, &[leaf0], &leaf1).unwrap();
+        assert_eq!(root_for_leaf1, expected_root);
+    }
+
+    #[test]
+    fn valid_proof_for_three_leaf_tree() {
+        // RFC 6962 shape for 3 leaves: root = hash(hash(leaf0, leaf1), leaf2)
+        let leaf0 = leaf(1);
+        let leaf1 = leaf(2);
+        let leaf2 = leaf(3);
+        let left_subtree = hash_children(&leaf0, &leaf1);
+        let expected_root = hash_children(&left_subtree, &leaf2);
+
+        // leaf_index=2, tree_size=3: sibling is the whole left subtree
+        let root = root_from_inclusion_proof(2, 3, &[left_subtree], &leaf2).unwrap();
+        assert_eq!(root, expected_root);
+
+        // leaf_index=0, tree_size=3: proof is [leaf1, leaf2]
+        let root = root_from_inclusion_proof(0, 3, &[leaf1, leaf2], &leaf0).unwrap();
+        assert_eq!(root, expected_root);
+    }
+
+    #[test]
+    fn truncated_proof_is_rejected() {
+        let leaf0 = leaf(1);
+        // tree_size=2 requires exactly one proof element; give zero
+        let err = root_from_inclusion_proof(0, 2, &[], &leaf0).unwrap_err();
+        assert_eq!(err.code(), "E-LOG-002");
+    }
+
+    #[test]
+    fn over_long_proof_is_rejected() {
+        let leaf0 = leaf(1);
+        let leaf1 = leaf(2);
+        let extra = leaf(3);
+        // tree_size=2 only needs one sibling; a second entry overruns the tree height
+        let err = root_from_inclusion_proof(0, 2, &[leaf1, extra], &leaf0).unwrap_err();
+        assert_eq!(err.code(), "E-LOG-002");
+    }
+
+    #[test]
+    fn tampered_sibling_hash_yields_mismatched_root() {
+        let leaf0 = leaf(1);
+        let leaf1 = leaf(2);
+        let expected_root = hash_children(&leaf0, &leaf1);
+
+        let tampered_sibling = leaf(99);
+        let root = root_from_inclusion_proof(0, 2, &[tampered_sibling], &leaf0).unwrap();
+        assert_ne!(root, expected_root);
+    }
+}